@@ -1,13 +1,120 @@
-use pyo3::exceptions::PyTypeError;
+use std::collections::HashMap;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyBytes, PyDict, PyList, PyString};
 use yrs::types::text::TextEvent;
-use yrs::{Subscription, Text, Transaction};
+use yrs::types::Attrs;
+use yrs::{Assoc, StickyIndex, Subscription, Text, Transaction};
 
 use crate::shared_types::SharedType;
-use crate::type_conversions::ToPython;
+use crate::type_conversions::{PyObjectWrapper, ToPython};
 use crate::y_transaction::YTransaction;
 
+/// Converts a Python dict of formatting attributes (eg. `{"bold": True}`) into the `Attrs`
+/// map expected by the underlying `yrs::Text` formatting API.
+fn attrs_from_dict(dict: &PyDict) -> Attrs {
+    let mut attrs = HashMap::with_capacity(dict.len());
+    for (key, value) in dict.iter() {
+        let key: String = key.extract().unwrap_or_else(|_| key.to_string());
+        let value = PyObjectWrapper(value.to_object(value.py())).into();
+        attrs.insert(key.into_boxed_str(), value);
+    }
+    attrs
+}
+
+/// Selects how the `index`/`length` arguments of a `YText` method are counted: `"utf8"` (the
+/// default, and the unit `yrs` itself stores internally), `"utf16"` (to interoperate with
+/// JS/browser peers such as ywasm, which index strings in UTF-16 code units), or `"codepoint"`
+/// (natural Python string indexing).
+#[derive(Clone, Copy)]
+enum OffsetKind {
+    Bytes,
+    Utf16,
+    CodePoint,
+}
+
+impl OffsetKind {
+    fn parse(kind: Option<&str>) -> PyResult<Self> {
+        match kind.unwrap_or("utf8") {
+            "utf8" => Ok(OffsetKind::Bytes),
+            "utf16" => Ok(OffsetKind::Utf16),
+            "codepoint" => Ok(OffsetKind::CodePoint),
+            other => Err(PyValueError::new_err(format!(
+                "Unrecognized offset_kind '{}', expected one of: utf8, utf16, codepoint",
+                other
+            ))),
+        }
+    }
+}
+
+/// Translates `index`, counted in units of `kind`, into the UTF-8 byte offset into `content`
+/// that `yrs` expects. A no-op when `kind` is already `Bytes`.
+fn translate_offset(content: &str, index: u32, kind: OffsetKind) -> u32 {
+    match kind {
+        OffsetKind::Bytes => index,
+        OffsetKind::CodePoint => content
+            .char_indices()
+            .nth(index as usize)
+            .map(|(byte_idx, _)| byte_idx as u32)
+            .unwrap_or(content.len() as u32),
+        OffsetKind::Utf16 => {
+            let mut utf16_units = 0u32;
+            for (byte_idx, ch) in content.char_indices() {
+                if utf16_units >= index {
+                    return byte_idx as u32;
+                }
+                utf16_units += ch.len_utf16() as u32;
+            }
+            content.len() as u32
+        }
+    }
+}
+
+/// Tags an `apply_delta` op by how it affects the replay cursor, carrying its length in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeltaCursorOp {
+    Insert(u32),
+    Retain(u32),
+    Delete(u32),
+}
+
+/// Advances `index` according to `op`'s effect on `apply_delta`'s cursor: `Insert`/`Retain`
+/// move the cursor past their length, while `Delete` removes at `index` without advancing
+/// past it (the deleted range is gone, so the next op still lands at the same spot).
+fn advance_cursor(index: u32, op: DeltaCursorOp) -> u32 {
+    match op {
+        DeltaCursorOp::Insert(len) | DeltaCursorOp::Retain(len) => index + len,
+        DeltaCursorOp::Delete(_) => index,
+    }
+}
+
+/// Translates a UTF-8 `byte_offset` into `content` back into units of `kind`. The inverse of
+/// `translate_offset`, used to resolve a sticky index back into the caller's indexing scheme.
+fn byte_offset_to_kind(content: &str, byte_offset: u32, kind: OffsetKind) -> u32 {
+    let prefix = &content[..(byte_offset as usize).min(content.len())];
+    match kind {
+        OffsetKind::Bytes => byte_offset,
+        OffsetKind::CodePoint => prefix.chars().count() as u32,
+        OffsetKind::Utf16 => prefix.encode_utf16().count() as u32,
+    }
+}
+
+/// `Text::to_string` omits embedded content inserted via `insert_embed` entirely, which would
+/// silently misalign every `translate_offset`/`byte_offset_to_kind` call at or after an embed
+/// (and risks a non-char-boundary slice panic). `declared_length` should be the type's own
+/// `length` (byte length including embeds); if it doesn't match `content`'s byte length, the
+/// type contains embeds and a non-`"utf8"` `offset_kind` cannot be resolved against `content`.
+fn check_content_covers_length(content: &str, declared_length: u32) -> PyResult<()> {
+    if content.len() as u32 != declared_length {
+        return Err(PyValueError::new_err(
+            "Cannot translate a non-utf8 offset_kind for a YText containing embedded content \
+             inserted via insert_embed; use offset_kind=\"utf8\" instead",
+        ));
+    }
+    Ok(())
+}
+
 /// A shared data type used for collaborative text editing. It enables multiple users to add and
 /// remove chunks of text in efficient manner. This type is internally represented as a mutable
 /// double-linked list of text chunks - an optimization occurs during `YTransaction.commit`, which
@@ -30,6 +137,49 @@ impl From<Text> for YText {
     }
 }
 
+impl YText {
+    /// Resolves an `index` counted in units of `offset_kind` down to the UTF-8 byte offset
+    /// that the underlying `yrs::Text` expects.
+    fn resolve_offset(
+        &self,
+        txn: &YTransaction,
+        index: u32,
+        offset_kind: Option<&str>,
+    ) -> PyResult<u32> {
+        let kind = OffsetKind::parse(offset_kind)?;
+        Ok(match kind {
+            OffsetKind::Bytes => index,
+            _ => {
+                let content = self.to_string(txn);
+                check_content_covers_length(&content, self.length())?;
+                translate_offset(&content, index, kind)
+            }
+        })
+    }
+
+    /// Resolves an `(index, length)` range counted in units of `offset_kind` down to the
+    /// UTF-8 byte `(index, length)` range that the underlying `yrs::Text` expects.
+    fn resolve_range(
+        &self,
+        txn: &YTransaction,
+        index: u32,
+        length: u32,
+        offset_kind: Option<&str>,
+    ) -> PyResult<(u32, u32)> {
+        let kind = OffsetKind::parse(offset_kind)?;
+        Ok(match kind {
+            OffsetKind::Bytes => (index, length),
+            _ => {
+                let content = self.to_string(txn);
+                check_content_covers_length(&content, self.length())?;
+                let start = translate_offset(&content, index, kind);
+                let end = translate_offset(&content, index + length, kind);
+                (start, end - start)
+            }
+        })
+    }
+}
+
 #[pymethods]
 impl YText {
     /// Creates a new preliminary instance of a `YText` shared data type, with its state initialized
@@ -66,6 +216,25 @@ impl YText {
         }
     }
 
+    /// Returns the length of this `YText` instance, counted in units of `offset_kind`
+    /// (`"utf8"`, `"utf16"` or `"codepoint"`; defaults to `"utf8"`, same as `length`).
+    #[args(offset_kind = "None")]
+    pub fn length_in(&self, txn: &YTransaction, offset_kind: Option<&str>) -> PyResult<u32> {
+        let kind = OffsetKind::parse(offset_kind)?;
+        Ok(match kind {
+            OffsetKind::Bytes => self.length(),
+            _ => {
+                let content = self.to_string(txn);
+                check_content_covers_length(&content, self.length())?;
+                match kind {
+                    OffsetKind::CodePoint => content.chars().count() as u32,
+                    OffsetKind::Utf16 => content.encode_utf16().count() as u32,
+                    OffsetKind::Bytes => unreachable!(),
+                }
+            }
+        })
+    }
+
     /// Returns an underlying shared string stored in this data type.
     pub fn to_string(&self, txn: &YTransaction) -> String {
         match &self.0 {
@@ -83,11 +252,108 @@ impl YText {
     }
 
     /// Inserts a given `chunk` of text into this `YText` instance, starting at a given `index`.
-    pub fn insert(&mut self, txn: &mut YTransaction, index: u32, chunk: &str) {
+    /// `index` is counted in units of `offset_kind` (`"utf8"`, `"utf16"` or `"codepoint"`;
+    /// defaults to `"utf8"`, matching this type's `length`).
+    #[args(offset_kind = "None")]
+    pub fn insert(
+        &mut self,
+        txn: &mut YTransaction,
+        index: u32,
+        chunk: &str,
+        offset_kind: Option<&str>,
+    ) -> PyResult<()> {
+        let index = self.resolve_offset(&*txn, index, offset_kind)?;
         match &mut self.0 {
             SharedType::Integrated(v) => v.insert(txn, index, chunk),
             SharedType::Prelim(v) => v.insert_str(index as usize, chunk),
         }
+        Ok(())
+    }
+
+    /// Inserts a given `chunk` of text into this `YText` instance, starting at a given `index`,
+    /// formatting it with the provided `attrs`. `attrs` is a Python dict mapping attribute names
+    /// to JSON-serializable values, eg. `{"bold": True, "color": "#ff0000"}`. `index` is counted
+    /// in units of `offset_kind` (`"utf8"`, `"utf16"` or `"codepoint"`; defaults to `"utf8"`).
+    #[args(offset_kind = "None")]
+    pub fn insert_with_attributes(
+        &mut self,
+        txn: &mut YTransaction,
+        index: u32,
+        chunk: &str,
+        attrs: &PyDict,
+        offset_kind: Option<&str>,
+    ) -> PyResult<()> {
+        let attrs = attrs_from_dict(attrs);
+        let index = self.resolve_offset(&*txn, index, offset_kind)?;
+        match &mut self.0 {
+            SharedType::Integrated(v) => {
+                v.insert_with_attributes(txn, index, chunk, attrs);
+                Ok(())
+            }
+            SharedType::Prelim(_) => Err(PyTypeError::new_err(
+                "Cannot insert formatted text into a preliminary type. Must be added to a YDoc first",
+            )),
+        }
+    }
+
+    /// Formats a range of this `YText` instance, starting at a given `index` and spanning
+    /// `length` units, applying the provided `attrs`. `attrs` is a Python dict mapping
+    /// attribute names to JSON-serializable values, eg. `{"bold": True, "color": "#ff0000"}`.
+    /// `index` and `length` are counted in units of `offset_kind` (`"utf8"`, `"utf16"` or
+    /// `"codepoint"`; defaults to `"utf8"`, matching this type's `length`).
+    #[args(offset_kind = "None")]
+    pub fn format(
+        &mut self,
+        txn: &mut YTransaction,
+        index: u32,
+        length: u32,
+        attrs: &PyDict,
+        offset_kind: Option<&str>,
+    ) -> PyResult<()> {
+        let attrs = attrs_from_dict(attrs);
+        let (index, length) = self.resolve_range(&*txn, index, length, offset_kind)?;
+        match &mut self.0 {
+            SharedType::Integrated(v) => {
+                v.format(txn, index, length, attrs);
+                Ok(())
+            }
+            SharedType::Prelim(_) => Err(PyTypeError::new_err(
+                "Cannot format a preliminary type. Must be added to a YDoc first",
+            )),
+        }
+    }
+
+    /// Inserts a non-text `content` (eg. an image reference or other JSON-serializable value)
+    /// as an embedded block at a given `index`, optionally formatted with `attrs`. `content`
+    /// may be any Python value accepted by the `YMap`/`YArray` type conversions (dicts, lists,
+    /// scalars). Unlike `insert`, the embedded value is stored and later observed as a single
+    /// opaque unit rather than a run of characters. `index` is counted in units of
+    /// `offset_kind` (`"utf8"`, `"utf16"` or `"codepoint"`; defaults to `"utf8"`).
+    #[args(attrs = "None", offset_kind = "None")]
+    pub fn insert_embed(
+        &mut self,
+        txn: &mut YTransaction,
+        index: u32,
+        content: PyObject,
+        attrs: Option<&PyDict>,
+        offset_kind: Option<&str>,
+    ) -> PyResult<()> {
+        let content: lib0::any::Any = PyObjectWrapper(content).into();
+        let index = self.resolve_offset(&*txn, index, offset_kind)?;
+        match &mut self.0 {
+            SharedType::Integrated(v) => {
+                match attrs {
+                    Some(attrs) => {
+                        v.insert_embed_with_attributes(txn, index, content, attrs_from_dict(attrs))
+                    }
+                    None => v.insert_embed(txn, index, content),
+                }
+                Ok(())
+            }
+            SharedType::Prelim(_) => Err(PyTypeError::new_err(
+                "Cannot insert an embed into a preliminary type. Must be added to a YDoc first",
+            )),
+        }
     }
 
     /// Appends a given `chunk` of text at the end of current `YText` instance.
@@ -99,14 +365,97 @@ impl YText {
     }
 
     /// Deletes a specified range of of characters, starting at a given `index`.
-    /// Both `index` and `length` are counted in terms of a number of UTF-8 character bytes.
-    pub fn delete(&mut self, txn: &mut YTransaction, index: u32, length: u32) {
+    /// Both `index` and `length` are counted in units of `offset_kind` (`"utf8"`, `"utf16"` or
+    /// `"codepoint"`; defaults to `"utf8"`, ie. a number of UTF-8 character bytes).
+    #[args(offset_kind = "None")]
+    pub fn delete(
+        &mut self,
+        txn: &mut YTransaction,
+        index: u32,
+        length: u32,
+        offset_kind: Option<&str>,
+    ) -> PyResult<()> {
+        let (index, length) = self.resolve_range(&*txn, index, length, offset_kind)?;
         match &mut self.0 {
             SharedType::Integrated(v) => v.remove_range(txn, index, length),
             SharedType::Prelim(v) => {
                 v.drain((index as usize)..(index + length) as usize);
             }
         }
+        Ok(())
+    }
+
+    /// Applies a Quill/Yjs-style `delta` to this `YText` instance in a single batch, where
+    /// `delta` is a Python list of ops in the same shape emitted by `YTextEvent.delta`:
+    /// `{"retain": n, "attributes": {...}?}`, `{"insert": str|object, "attributes": {...}?}` or
+    /// `{"delete": n}`. Ops are replayed in order against a cursor starting at index 0: `retain`
+    /// advances the cursor (optionally re-formatting the retained range), `insert` inserts text
+    /// or an embed at the cursor then advances past it, and `delete` removes `n` units at the
+    /// cursor without advancing.
+    pub fn apply_delta(&mut self, txn: &mut YTransaction, delta: Vec<&PyDict>) -> PyResult<()> {
+        let mut index = 0u32;
+        for op in delta {
+            if let Some(insert) = op.get_item("insert") {
+                let attrs = op.get_item("attributes").map(|a| a.downcast()).transpose()?;
+                if let Ok(chunk) = insert.downcast::<PyString>() {
+                    let chunk = chunk.to_str()?;
+                    match attrs {
+                        Some(attrs) => self.insert_with_attributes(txn, index, chunk, attrs, None)?,
+                        None => self.insert(txn, index, chunk, None)?,
+                    }
+                    index = advance_cursor(index, DeltaCursorOp::Insert(chunk.len() as u32));
+                } else {
+                    self.insert_embed(txn, index, insert.into(), attrs, None)?;
+                    index = advance_cursor(index, DeltaCursorOp::Insert(1));
+                }
+            } else if let Some(retain) = op.get_item("retain") {
+                let len: u32 = retain.extract()?;
+                if let Some(attrs) = op.get_item("attributes") {
+                    self.format(txn, index, len, attrs.downcast()?, None)?;
+                }
+                index = advance_cursor(index, DeltaCursorOp::Retain(len));
+            } else if let Some(delete) = op.get_item("delete") {
+                let len: u32 = delete.extract()?;
+                self.delete(txn, index, len, None)?;
+                index = advance_cursor(index, DeltaCursorOp::Delete(len));
+            } else {
+                return Err(PyTypeError::new_err(
+                    "Each delta operation must contain one of: insert, retain, delete",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates a sticky index: an opaque `YStickyIndex` bound to the block/ID adjacent to
+    /// `index` rather than to a plain offset, so it keeps pointing at the same logical position
+    /// as concurrent edits shift the text around it. `assoc` selects which side of that block
+    /// the index clings to: `-1` binds it to the character before `index`, `+1` to the
+    /// character at or after it. `index` is counted in units of `offset_kind` (`"utf8"`,
+    /// `"utf16"` or `"codepoint"`; defaults to `"utf8"`). Useful for tracking cursors and
+    /// selections across edits.
+    #[args(offset_kind = "None")]
+    pub fn sticky_index(
+        &self,
+        txn: &mut YTransaction,
+        index: u32,
+        assoc: i32,
+        offset_kind: Option<&str>,
+    ) -> PyResult<YStickyIndex> {
+        let index = self.resolve_offset(&*txn, index, offset_kind)?;
+        let assoc = if assoc >= 0 { Assoc::After } else { Assoc::Before };
+        match &self.0 {
+            SharedType::Integrated(v) => match v.sticky_index(txn, index, assoc) {
+                Some(pos) => Ok(YStickyIndex {
+                    pos,
+                    scope: v.clone(),
+                }),
+                None => Err(PyValueError::new_err("Index out of bounds")),
+            },
+            SharedType::Prelim(_) => Err(PyTypeError::new_err(
+                "Cannot create a sticky index for a preliminary type. Must be added to a YDoc first",
+            )),
+        }
     }
 
     pub fn observe(&mut self, f: PyObject) -> PyResult<YTextObserver> {
@@ -182,7 +531,8 @@ impl YTextEvent {
     /// Returns a list of text changes made over corresponding `YText` collection within
     /// bounds of current transaction. These changes follow a format:
     ///
-    /// - { insert: string, attributes: any|undefined }
+    /// - { insert: string|object, attributes: any|undefined } (`object` for embedded content
+    ///   inserted via `YText.insert_embed`)
     /// - { delete: number }
     /// - { retain: number, attributes: any|undefined }
     #[getter]
@@ -213,3 +563,147 @@ impl From<Subscription<TextEvent>> for YTextObserver {
         YTextObserver(o)
     }
 }
+
+/// A stable, relative position within a `YText` instance, created via `YText.sticky_index`.
+/// Unlike a plain integer offset, a sticky index is bound to the block/ID next to the position
+/// it was created at, so it keeps resolving to the same logical place even as concurrent edits
+/// shift the surrounding text. Carries the `Text` it was created from, so `get_offset` always
+/// resolves against the scope it actually belongs to rather than a caller-supplied one that
+/// might not match.
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct YStickyIndex {
+    pos: StickyIndex,
+    scope: Text,
+}
+
+#[pymethods]
+impl YStickyIndex {
+    /// Resolves this sticky index against the current state of the `YText` it was created from,
+    /// returning its concrete offset, or `None` if the position it was bound to has since been
+    /// removed. `offset_kind` (`"utf8"`, `"utf16"` or `"codepoint"`; defaults to `"utf8"`)
+    /// selects the units the returned offset is counted in, mirroring the `offset_kind` the
+    /// index was created with via `YText.sticky_index`.
+    #[args(offset_kind = "None")]
+    pub fn get_offset(&self, txn: &YTransaction, offset_kind: Option<&str>) -> PyResult<Option<u32>> {
+        let byte_offset = match self.pos.get_offset(txn).map(|pos| pos.index) {
+            Some(byte_offset) => byte_offset,
+            None => return Ok(None),
+        };
+        let kind = OffsetKind::parse(offset_kind)?;
+        let offset = match kind {
+            OffsetKind::Bytes => byte_offset,
+            _ => {
+                let content = self.scope.to_string(txn);
+                check_content_covers_length(&content, self.scope.len())?;
+                byte_offset_to_kind(&content, byte_offset, kind)
+            }
+        };
+        Ok(Some(offset))
+    }
+
+    /// Serializes this sticky index into a binary payload, so it can be persisted or sent to
+    /// another peer and later restored with `decode`.
+    pub fn encode<'p>(&self, py: Python<'p>) -> &'p PyBytes {
+        PyBytes::new(py, &self.pos.encode_v1())
+    }
+
+    /// Restores a `YStickyIndex` previously serialized with `encode`, rebinding it to `scope`
+    /// (the `YText` it was originally created from - restoring against a different `YText`
+    /// produces a position that will never resolve to a meaningful offset).
+    #[staticmethod]
+    pub fn decode(data: &[u8], scope: &YText) -> PyResult<Self> {
+        let pos = StickyIndex::decode_v1(data).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        match &scope.0 {
+            SharedType::Integrated(v) => Ok(YStickyIndex { pos, scope: v.clone() }),
+            SharedType::Prelim(_) => Err(PyTypeError::new_err(
+                "Cannot decode a sticky index against a preliminary type. Must be added to a YDoc first",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translate_offset_utf8_is_a_no_op() {
+        assert_eq!(translate_offset("héllo", 3, OffsetKind::Bytes), 3);
+    }
+
+    #[test]
+    fn translate_offset_codepoint_accounts_for_multi_byte_chars() {
+        // "héllo": h(1 byte), é(2 bytes), l, l, o - codepoint index 2 ('l') is byte index 3.
+        assert_eq!(translate_offset("héllo", 2, OffsetKind::CodePoint), 3);
+    }
+
+    #[test]
+    fn translate_offset_utf16_accounts_for_supplementary_plane_chars() {
+        // "a😀b": 'a' is 1 UTF-16 unit, the emoji is a surrogate pair (2 units, 4 UTF-8 bytes),
+        // 'b' is 1 unit. UTF-16 index 3 (past the surrogate pair) must land on 'b', byte index 5.
+        assert_eq!(translate_offset("a😀b", 3, OffsetKind::Utf16), 5);
+    }
+
+    #[test]
+    fn translate_offset_out_of_range_clamps_to_content_length() {
+        assert_eq!(translate_offset("abc", 100, OffsetKind::CodePoint), 3);
+        assert_eq!(translate_offset("abc", 100, OffsetKind::Utf16), 3);
+    }
+
+    #[test]
+    fn check_content_covers_length_accepts_plain_text() {
+        assert!(check_content_covers_length("héllo", 6).is_ok());
+    }
+
+    #[test]
+    fn check_content_covers_length_rejects_embeds() {
+        // An embed inserted via insert_embed adds to the type's byte length but is omitted from
+        // to_string, so content.len() falls short of the declared length.
+        assert!(check_content_covers_length("ab", 3).is_err());
+    }
+
+    #[test]
+    fn byte_offset_to_kind_is_the_inverse_of_translate_offset() {
+        let content = "a😀b";
+        let byte_offset = translate_offset(content, 3, OffsetKind::Utf16);
+        assert_eq!(byte_offset_to_kind(content, byte_offset, OffsetKind::Utf16), 3);
+
+        let content = "héllo";
+        let byte_offset = translate_offset(content, 2, OffsetKind::CodePoint);
+        assert_eq!(
+            byte_offset_to_kind(content, byte_offset, OffsetKind::CodePoint),
+            2
+        );
+    }
+
+    #[test]
+    fn advance_cursor_insert_and_retain_move_past_their_length() {
+        assert_eq!(advance_cursor(5, DeltaCursorOp::Insert(3)), 8);
+        assert_eq!(advance_cursor(5, DeltaCursorOp::Retain(3)), 8);
+    }
+
+    #[test]
+    fn advance_cursor_delete_does_not_advance() {
+        assert_eq!(advance_cursor(5, DeltaCursorOp::Delete(3)), 5);
+    }
+
+    #[test]
+    fn apply_delta_cursor_walks_retain_insert_delete_in_order() {
+        // Mirrors apply_delta's own bookkeeping for [{retain: 5}, {insert: <3 bytes>}, {delete: 2}]:
+        // the delete must land right after the inserted text, not be shifted past it.
+        let ops = [
+            DeltaCursorOp::Retain(5),
+            DeltaCursorOp::Insert(3),
+            DeltaCursorOp::Delete(2),
+        ];
+        let mut index = 0u32;
+        let mut positions = Vec::with_capacity(ops.len());
+        for op in ops {
+            positions.push(index);
+            index = advance_cursor(index, op);
+        }
+        assert_eq!(positions, vec![0, 5, 8]);
+        assert_eq!(index, 8);
+    }
+}
@@ -0,0 +1,187 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use yrs::undo::{Event as YrsUndoEvent, EventKind, Options as UndoOptions, UndoManager as YrsUndoManager};
+use yrs::{Origin, Subscription, Transaction};
+
+use crate::shared_types::SharedType;
+use crate::y_text::YText;
+use crate::y_transaction::YTransaction;
+
+/// Tracks edits made to one or more shared types (eg. `YText`) and allows undoing/redoing them
+/// as a user would expect from a text editor. Consecutive local edits that happen within
+/// `capture_timeout_millis` of each other are coalesced into a single undoable stack item, and
+/// only changes made in transactions whose origin is in `origins` (or any origin, if `origins`
+/// is not provided) are captured - this lets remote peer updates pass through without being
+/// undoable.
+#[pyclass(unsendable)]
+pub struct YUndoManager {
+    manager: YrsUndoManager<()>,
+    // yrs subscriptions unsubscribe on drop, so these must be kept alive for as long as the
+    // registered callbacks should keep firing.
+    on_item_added_sub: Option<Subscription<YrsUndoEvent<()>>>,
+    on_item_popped_sub: Option<Subscription<YrsUndoEvent<()>>>,
+}
+
+#[pymethods]
+impl YUndoManager {
+    #[new]
+    #[args(capture_timeout_millis = "500", origins = "None")]
+    pub fn new(
+        txn: &mut YTransaction,
+        scope: &YText,
+        capture_timeout_millis: u64,
+        origins: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        let branch = match &scope.0 {
+            SharedType::Integrated(v) => v.clone(),
+            SharedType::Prelim(_) => {
+                return Err(PyTypeError::new_err(
+                    "Cannot track a preliminary type. Must be added to a YDoc first",
+                ))
+            }
+        };
+        let options = UndoOptions {
+            capture_timeout_millis,
+            ..UndoOptions::default()
+        };
+        let mut mgr = YrsUndoManager::with_options(txn, &branch, options);
+        if let Some(origins) = origins {
+            for origin in origins {
+                mgr.include_origin(Origin::from(origin));
+            }
+        }
+        Ok(YUndoManager {
+            manager: mgr,
+            on_item_added_sub: None,
+            on_item_popped_sub: None,
+        })
+    }
+
+    /// Adds another shared type to the scope tracked by this undo manager, so that edits to it
+    /// are captured alongside the types it was constructed with.
+    pub fn expand_scope(&mut self, scope: &YText) -> PyResult<()> {
+        match &scope.0 {
+            SharedType::Integrated(v) => {
+                self.manager.expand_scope(v);
+                Ok(())
+            }
+            SharedType::Prelim(_) => Err(PyTypeError::new_err(
+                "Cannot track a preliminary type. Must be added to a YDoc first",
+            )),
+        }
+    }
+
+    /// Undoes the most recent captured stack item, returning `True` if a change was undone.
+    pub fn undo(&mut self) -> PyResult<bool> {
+        self.manager
+            .undo()
+            .map_err(|e| PyTypeError::new_err(e.to_string()))
+    }
+
+    /// Redoes the most recently undone stack item, returning `True` if a change was redone.
+    pub fn redo(&mut self) -> PyResult<bool> {
+        self.manager
+            .redo()
+            .map_err(|e| PyTypeError::new_err(e.to_string()))
+    }
+
+    /// Returns `True` if there is a captured stack item available to `undo`.
+    pub fn can_undo(&self) -> bool {
+        self.manager.can_undo()
+    }
+
+    /// Returns `True` if there is a captured stack item available to `redo`.
+    pub fn can_redo(&self) -> bool {
+        self.manager.can_redo()
+    }
+
+    /// Stops capturing further changes into the current stack item. The next captured change
+    /// starts a new stack item, regardless of how soon it follows the previous one.
+    pub fn stop_capturing(&mut self) {
+        self.manager.reset();
+    }
+
+    /// Clears both the undo and redo stacks, discarding all captured history.
+    pub fn clear(&mut self) -> PyResult<()> {
+        self.manager
+            .clear()
+            .map_err(|e| PyTypeError::new_err(e.to_string()))
+    }
+
+    /// Registers a callback invoked every time a new stack item is captured (pushed onto the
+    /// undo stack). The callback receives a `YUndoEvent` describing the change. Replaces any
+    /// previously registered callback, whose subscription is dropped (and thus unsubscribed).
+    pub fn on_stack_item_added(&mut self, f: PyObject) {
+        let sub = self.manager.on_item_added(move |txn, event| {
+            Python::with_gil(|py| {
+                let e = YUndoEvent::new(event, txn);
+                if let Err(err) = f.call1(py, (e,)) {
+                    err.restore(py)
+                }
+            });
+        });
+        self.on_item_added_sub = Some(sub);
+    }
+
+    /// Registers a callback invoked every time a stack item is popped off the undo or redo
+    /// stack (ie. whenever `undo` or `redo` applies a change). The callback receives a
+    /// `YUndoEvent` describing the change. Replaces any previously registered callback, whose
+    /// subscription is dropped (and thus unsubscribed).
+    pub fn on_stack_item_popped(&mut self, f: PyObject) {
+        let sub = self.manager.on_item_popped(move |txn, event| {
+            Python::with_gil(|py| {
+                let e = YUndoEvent::new(event, txn);
+                if let Err(err) = f.call1(py, (e,)) {
+                    err.restore(py)
+                }
+            });
+        });
+        self.on_item_popped_sub = Some(sub);
+    }
+}
+
+/// Event passed to `YUndoManager.on_stack_item_added`/`on_stack_item_popped` callbacks,
+/// describing the stack item that was just pushed or popped.
+#[pyclass(unsendable)]
+pub struct YUndoEvent {
+    inner: *const YrsUndoEvent<()>,
+    txn: *const Transaction,
+}
+
+impl YUndoEvent {
+    fn new(event: &YrsUndoEvent<()>, txn: &Transaction) -> Self {
+        YUndoEvent {
+            inner: event as *const YrsUndoEvent<()>,
+            txn: txn as *const Transaction,
+        }
+    }
+
+    fn inner(&self) -> &YrsUndoEvent<()> {
+        unsafe { self.inner.as_ref().unwrap() }
+    }
+
+    #[allow(dead_code)]
+    fn txn(&self) -> &Transaction {
+        unsafe { self.txn.as_ref().unwrap() }
+    }
+}
+
+#[pymethods]
+impl YUndoEvent {
+    /// Returns `"undo"` if this item was popped off the undo stack (ie. applied by `undo()`),
+    /// or `"redo"` if it was popped off the redo stack (ie. applied by `redo()`) - or, for
+    /// `on_stack_item_added`, which stack the newly captured item was pushed onto.
+    #[getter]
+    pub fn kind(&self) -> &'static str {
+        match self.inner().kind() {
+            EventKind::Undo => "undo",
+            EventKind::Redo => "redo",
+        }
+    }
+
+    /// Returns the origin of the transaction that produced this stack item, if any.
+    #[getter]
+    pub fn origin(&self) -> Option<String> {
+        self.inner().origin().map(|o| o.to_string())
+    }
+}